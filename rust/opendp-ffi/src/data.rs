@@ -1,58 +1,419 @@
 use std::collections::HashMap;
-use std::os::raw::c_char;
+use std::convert::TryFrom;
+use std::os::raw::{c_char, c_void};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
 
 use opendp::data::Column;
 
-use crate::core::FfiObject;
+use crate::core::{FfiObject, Type};
 use crate::util;
 
+/// Reads a flat C representation of `Self` out of `raw_ptr`/`len` using the
+/// fat-pointer convention already established by this module: a bare pointer
+/// for a fixed-size scalar, or a pointer plus an element/byte count for a
+/// slice-like value. Backs `opendp_data__load`.
+trait FlatLoad: Sized {
+    unsafe fn load(raw_ptr: *const c_void, len: usize) -> Self;
+}
+
+/// Writes `Self` back out to a flat C representation. Backs `opendp_data__store`.
+trait FlatStore {
+    /// Number of elements (for slices/strings) or `1` (for scalars) needed
+    /// to hold this value. Lets a caller size its buffer before the copy.
+    fn flat_len(&self) -> usize;
+    unsafe fn store(&self, out_ptr: *mut c_void);
+}
+
+macro_rules! impl_flat_scalar {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl FlatLoad for $ty {
+                unsafe fn load(raw_ptr: *const c_void, _len: usize) -> Self {
+                    *(raw_ptr as *const $ty)
+                }
+            }
+            impl FlatStore for $ty {
+                fn flat_len(&self) -> usize { 1 }
+                unsafe fn store(&self, out_ptr: *mut c_void) {
+                    *(out_ptr as *mut $ty) = *self;
+                }
+            }
+        )+
+    };
+}
+impl_flat_scalar!(u32, u64, i32, i64, f32, f64, u8);
+
+impl FlatLoad for bool {
+    unsafe fn load(raw_ptr: *const c_void, _len: usize) -> Self {
+        // A caller-supplied byte can be anything; reading it directly as a
+        // `bool` would be UB unless it happens to be 0 or 1. Go through `u8`
+        // and a checked conversion instead, treating any nonzero byte as `true`.
+        *(raw_ptr as *const u8) != 0
+    }
+}
+impl FlatStore for bool {
+    fn flat_len(&self) -> usize {
+        1
+    }
+    unsafe fn store(&self, out_ptr: *mut c_void) {
+        *(out_ptr as *mut u8) = *self as u8;
+    }
+}
+
+impl FlatLoad for String {
+    unsafe fn load(raw_ptr: *const c_void, len: usize) -> Self {
+        let bytes = std::slice::from_raw_parts(raw_ptr as *const u8, len);
+        String::from_utf8_lossy(bytes).into_owned()
+    }
+}
+impl FlatStore for String {
+    fn flat_len(&self) -> usize {
+        self.as_bytes().len()
+    }
+    unsafe fn store(&self, out_ptr: *mut c_void) {
+        std::ptr::copy_nonoverlapping(self.as_ptr(), out_ptr as *mut u8, self.as_bytes().len());
+    }
+}
+
+macro_rules! impl_flat_vec {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl FlatLoad for Vec<$ty> {
+                unsafe fn load(raw_ptr: *const c_void, len: usize) -> Self {
+                    std::slice::from_raw_parts(raw_ptr as *const $ty, len).to_vec()
+                }
+            }
+            impl FlatStore for Vec<$ty> {
+                fn flat_len(&self) -> usize { self.len() }
+                unsafe fn store(&self, out_ptr: *mut c_void) {
+                    std::ptr::copy_nonoverlapping(self.as_ptr(), out_ptr as *mut $ty, self.len());
+                }
+            }
+        )+
+    };
+}
+impl_flat_vec!(u32, u64, i32, i64, f32, f64, u8);
+
+impl FlatLoad for Vec<bool> {
+    unsafe fn load(raw_ptr: *const c_void, len: usize) -> Self {
+        // Same reasoning as the scalar `bool` impl: go through `u8` rather
+        // than transmuting caller-supplied bytes straight into `bool`.
+        std::slice::from_raw_parts(raw_ptr as *const u8, len)
+            .iter()
+            .map(|&byte| byte != 0)
+            .collect()
+    }
+}
+impl FlatStore for Vec<bool> {
+    fn flat_len(&self) -> usize {
+        self.len()
+    }
+    unsafe fn store(&self, out_ptr: *mut c_void) {
+        let out = std::slice::from_raw_parts_mut(out_ptr as *mut u8, self.len());
+        for (dst, &value) in out.iter_mut().zip(self.iter()) {
+            *dst = value as u8;
+        }
+    }
+}
+
+/// Flat C representation of a smoothed max-divergence distance, i.e. an
+/// `(epsilon, delta)` pair. A bare `(f64, f64)` tuple has no guaranteed
+/// memory layout (Rust tuples are not `#[repr(C)]`), so this struct is the
+/// load/store counterpart callers on the C side actually write into.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct SmoothedMaxDivergenceDistance {
+    pub epsilon: f64,
+    pub delta: f64,
+}
+
+impl FlatLoad for SmoothedMaxDivergenceDistance {
+    unsafe fn load(raw_ptr: *const c_void, _len: usize) -> Self {
+        *(raw_ptr as *const SmoothedMaxDivergenceDistance)
+    }
+}
+impl FlatStore for SmoothedMaxDivergenceDistance {
+    fn flat_len(&self) -> usize {
+        1
+    }
+    unsafe fn store(&self, out_ptr: *mut c_void) {
+        *(out_ptr as *mut SmoothedMaxDivergenceDistance) = *self;
+    }
+}
+
+/// Table-driven replacement for the old per-type `from_f64`/`to_f64`/`from_string`/
+/// `distance_*` constructors: one load and one store entry point, keyed on a
+/// `Type` descriptor, that knows for every registered type how to read a flat
+/// C representation into an `FfiObject` and how to write it back out.
 #[no_mangle]
-pub extern "C" fn opendp_data__from_f64(p: f64) -> *mut FfiObject {
-    FfiObject::new(p)
+pub extern "C" fn opendp_data__load(
+    type_descriptor: *const c_char,
+    raw_ptr: *const c_void,
+    len: usize,
+) -> *mut FfiObject {
+    fn monomorphize<T: 'static + FlatLoad>(raw_ptr: *const c_void, len: usize) -> *mut FfiObject {
+        FfiObject::new(unsafe { T::load(raw_ptr, len) })
+    }
+    let type_arg = match Type::try_from(type_descriptor) {
+        Ok(type_arg) => type_arg,
+        Err(_) => return std::ptr::null_mut(),
+    };
+    dispatch!(monomorphize, [(type_arg, [
+        u32, u64, i32, i64, f32, f64, bool, u8, String,
+        Vec<u32>, Vec<u64>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<bool>, Vec<u8>,
+        SmoothedMaxDivergenceDistance
+    ])], (raw_ptr, len))
 }
 
+/// Companion to `opendp_data__load`. Call once with a null `out_ptr` to learn
+/// the required buffer size via `out_len`, then again with a buffer of that
+/// size to fill it. Returns `true` for every registered type; a `this` whose
+/// type is not in the list below is not turned into a `false` return here but
+/// falls through to `dispatch!`'s no-match arm.
 #[no_mangle]
-pub extern "C" fn opendp_data__to_f64(this: *mut FfiObject) -> f64 {
+pub extern "C" fn opendp_data__store(
+    this: *const FfiObject,
+    out_ptr: *mut c_void,
+    out_len: *mut usize,
+) -> bool {
+    fn monomorphize<T: 'static + FlatStore>(
+        this: &FfiObject,
+        out_ptr: *mut c_void,
+        out_len: *mut usize,
+    ) -> bool {
+        let this = this.as_ref::<T>();
+        if !out_len.is_null() {
+            unsafe { *out_len = this.flat_len() };
+        }
+        if !out_ptr.is_null() {
+            unsafe { this.store(out_ptr) };
+        }
+        true
+    }
     let this = util::as_ref(this);
-    *this.as_ref()
+    let type_arg = &this.type_;
+    dispatch!(monomorphize, [(type_arg, [
+        u32, u64, i32, i64, f32, f64, bool, u8, String,
+        Vec<u32>, Vec<u64>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<bool>, Vec<u8>,
+        SmoothedMaxDivergenceDistance
+    ])], (this, out_ptr, out_len))
 }
 
-#[no_mangle]
-pub extern "C" fn opendp_data__distance_hamming(d: u32) -> *mut FfiObject {
-    FfiObject::new(d)
+/// On-the-wire encoding selected by the `format` argument of `serialize`/`deserialize`.
+#[derive(Clone, Copy, PartialEq)]
+enum SerializationFormat {
+    /// Self-describing, human-inspectable. The default, and the only format
+    /// guaranteed stable across versions.
+    Json,
+    /// Compact, length-prefixed binary encoding. Smaller and faster, but not
+    /// guaranteed stable across releases of the `bincode` wire format.
+    Binary,
 }
 
-#[no_mangle]
-pub extern "C" fn opendp_data__distance_smoothed_max_divergence(epsilon: f64, delta: f64) -> *mut FfiObject {
-    FfiObject::new((epsilon, delta))
+impl SerializationFormat {
+    fn parse(format: *const c_char) -> Self {
+        match util::to_str(format) {
+            "binary" => SerializationFormat::Binary,
+            _ => SerializationFormat::Json,
+        }
+    }
 }
 
-#[no_mangle]
-pub extern "C" fn opendp_data__from_string(p: *const c_char) -> *mut FfiObject {
-    let s = util::to_str(p).to_owned();
-    FfiObject::new(s)
+/// Envelope written for the `Json` format: the type tag travels alongside
+/// the payload so `deserialize` does not need the caller to repeat it.
+#[derive(Serialize, Deserialize)]
+struct JsonEnvelope {
+    type_: String,
+    data: serde_json::Value,
+}
+
+/// A stable, hand-maintained wire tag for a type registered in the
+/// `serialize`/`deserialize` dispatch lists below.
+///
+/// `std::any::type_name::<T>()` is explicitly *not* guaranteed stable across
+/// compiler versions or even separate builds of the same source, so using it
+/// as the on-the-wire type discriminator would let a blob this library
+/// serializes today silently fail to deserialize after a toolchain or
+/// dependency bump. `WIRE_TAG` is instead a fixed string chosen by us, kept
+/// in lockstep with the dispatch lists the same way `Type` descriptors are.
+trait WireTag {
+    const WIRE_TAG: &'static str;
+}
+
+macro_rules! impl_wire_tag {
+    ($($ty:ty => $tag:expr),+ $(,)?) => {
+        $(
+            impl WireTag for $ty {
+                const WIRE_TAG: &'static str = $tag;
+            }
+        )+
+    };
+}
+
+impl_wire_tag!(
+    u32 => "u32", u64 => "u64", i32 => "i32", i64 => "i64", f32 => "f32", f64 => "f64",
+    bool => "bool", String => "String", u8 => "u8", Column => "Column",
+    Vec<u32> => "Vec<u32>", Vec<u64> => "Vec<u64>", Vec<i32> => "Vec<i32>", Vec<i64> => "Vec<i64>",
+    Vec<f32> => "Vec<f32>", Vec<f64> => "Vec<f64>", Vec<bool> => "Vec<bool>",
+    Vec<String> => "Vec<String>", Vec<u8> => "Vec<u8>", Vec<Column> => "Vec<Column>",
+    Vec<Vec<String>> => "Vec<Vec<String>>",
+    HashMap<String, Column> => "HashMap<String,Column>",
+    // The following are for Python demo use of compositions. Need to figure this out!!!
+    (Box<i32>, Box<f64>) => "(Box<i32>,Box<f64>)",
+    (Box<i32>, Box<u32>) => "(Box<i32>,Box<u32>)",
+    (Box<(Box<f64>, Box<f64>)>, Box<f64>) => "(Box<(Box<f64>,Box<f64>)>,Box<f64>)",
+);
+
+/// An owned, C-visible byte buffer returned by `opendp_data__serialize`.
+/// Must be released with `opendp_data__buffer_free` exactly once.
+#[repr(C)]
+pub struct FfiBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+    cap: usize,
+}
+
+impl FfiBuffer {
+    fn from_vec(mut bytes: Vec<u8>) -> *mut FfiBuffer {
+        let buffer = FfiBuffer {
+            data: bytes.as_mut_ptr(),
+            len: bytes.len(),
+            cap: bytes.capacity(),
+        };
+        std::mem::forget(bytes);
+        util::into_raw(buffer)
+    }
 }
 
 #[no_mangle]
-pub extern "C" fn opendp_data__to_string(this: *const FfiObject) -> *const c_char {
-    fn monomorphize<T: std::fmt::Debug>(this: &FfiObject) -> *const c_char {
+pub extern "C" fn opendp_data__serialize(
+    this: *const FfiObject,
+    format: *const c_char,
+) -> *mut FfiBuffer {
+    fn monomorphize<T: 'static + Serialize + WireTag>(
+        this: &FfiObject,
+        format: SerializationFormat,
+    ) -> Option<Vec<u8>> {
         let this = this.as_ref::<T>();
-        // FIXME: Figure out how to implement general to_string().
-        let string = format!("{:?}", this);
-        // FIXME: Leaks string.
-        util::into_c_char_p(string)
+        let type_tag = T::WIRE_TAG;
+        match format {
+            SerializationFormat::Json => {
+                let envelope = JsonEnvelope {
+                    type_: type_tag.to_string(),
+                    data: serde_json::to_value(this).ok()?,
+                };
+                serde_json::to_vec(&envelope).ok()
+            }
+            SerializationFormat::Binary => {
+                let payload = bincode::serialize(this).ok()?;
+                let mut bytes = Vec::with_capacity(4 + type_tag.len() + payload.len());
+                bytes.extend_from_slice(&(type_tag.len() as u32).to_le_bytes());
+                bytes.extend_from_slice(type_tag.as_bytes());
+                bytes.extend_from_slice(&payload);
+                Some(bytes)
+            }
+        }
     }
     let this = util::as_ref(this);
+    let format = SerializationFormat::parse(format);
     let type_arg = &this.type_;
-    dispatch!(monomorphize, [(type_arg, [
+    let bytes = dispatch!(monomorphize, [(type_arg, [
+        u32, u64, i32, i64, f32, f64, bool, String, u8, Column,
+        Vec<u32>, Vec<u64>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<bool>, Vec<String>, Vec<u8>, Vec<Column>, Vec<Vec<String>>,
+        HashMap<String, Column>,
+        // The following are for Python demo use of compositions. Need to figure this out!!!
+        (Box<i32>, Box<f64>),
+        (Box<i32>, Box<u32>),
+        (Box<(Box<f64>, Box<f64>)>, Box<f64>)
+    ])], (this, format));
+    match bytes {
+        Some(bytes) => FfiBuffer::from_vec(bytes),
+        None => std::ptr::null_mut(),
+    }
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_data__deserialize(
+    bytes: *const u8,
+    len: usize,
+    format: *const c_char,
+) -> *mut FfiObject {
+    // Carries whatever each format already has on hand after peeling off the
+    // type tag, so neither branch needs to round-trip through the other's
+    // representation: `Json` still holds the parsed `serde_json::Value` from
+    // the envelope, `Binary` holds the raw payload bytes.
+    enum Payload<'a> {
+        Json(serde_json::Value),
+        Binary(&'a [u8]),
+    }
+    fn monomorphize<T: 'static + DeserializeOwned>(payload: Payload) -> *mut FfiObject {
+        let value: Result<T, ()> = match payload {
+            Payload::Json(data) => serde_json::from_value(data).map_err(|_| ()),
+            Payload::Binary(bytes) => bincode::deserialize(bytes).map_err(|_| ()),
+        };
+        match value {
+            Ok(value) => FfiObject::new(value),
+            Err(_) => std::ptr::null_mut(),
+        }
+    }
+    // Mirrors the dispatch list in `opendp_data__serialize`: the stable
+    // `WireTag` recovered from the envelope/binary header picks the concrete
+    // type to deserialize into.
+    macro_rules! dispatch_by_tag {
+        ($tag:expr, $payload:expr, [$($ty:ty),+ $(,)?]) => {
+            match $tag {
+                $(t if t == <$ty as WireTag>::WIRE_TAG => monomorphize::<$ty>($payload),)+
+                _ => std::ptr::null_mut(),
+            }
+        };
+    }
+
+    let format = SerializationFormat::parse(format);
+    let slice = unsafe { std::slice::from_raw_parts(bytes, len) };
+
+    let (type_tag, payload) = match format {
+        SerializationFormat::Json => {
+            let envelope: JsonEnvelope = match serde_json::from_slice(slice) {
+                Ok(envelope) => envelope,
+                Err(_) => return std::ptr::null_mut(),
+            };
+            (envelope.type_, Payload::Json(envelope.data))
+        }
+        SerializationFormat::Binary => {
+            if slice.len() < 4 {
+                return std::ptr::null_mut();
+            }
+            let tag_len = u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]) as usize;
+            if slice.len() < 4 + tag_len {
+                return std::ptr::null_mut();
+            }
+            let type_tag = String::from_utf8_lossy(&slice[4..4 + tag_len]).into_owned();
+            (type_tag, Payload::Binary(&slice[4 + tag_len..]))
+        }
+    };
+
+    dispatch_by_tag!(type_tag.as_str(), payload, [
         u32, u64, i32, i64, f32, f64, bool, String, u8, Column,
         Vec<u32>, Vec<u64>, Vec<i32>, Vec<i64>, Vec<f32>, Vec<f64>, Vec<bool>, Vec<String>, Vec<u8>, Vec<Column>, Vec<Vec<String>>,
         HashMap<String, Column>,
-        // FIXME: The following are for Python demo use of compositions. Need to figure this out!!!
+        // The following are for Python demo use of compositions. Need to figure this out!!!
         (Box<i32>, Box<f64>),
         (Box<i32>, Box<u32>),
         (Box<(Box<f64>, Box<f64>)>, Box<f64>)
-    ])], (this))
+    ])
+}
+
+#[no_mangle]
+pub extern "C" fn opendp_data__buffer_free(this: *mut FfiBuffer) {
+    if this.is_null() {
+        return;
+    }
+    unsafe {
+        let buffer = Box::from_raw(this);
+        drop(Vec::from_raw_parts(buffer.data, buffer.len, buffer.cap));
+    }
 }
 
 #[no_mangle]
@@ -65,10 +426,199 @@ pub extern "C" fn opendp_data__bootstrap() -> *const c_char {
     let spec =
 r#"{
 "functions": [
-    { "name": "from_string", "args": [ ["const char *", "s"] ], "ret": "FfiObject *" },
-    { "name": "to_string", "args": [ ["const FfiObject *", "this"] ], "ret": "const char *" },
+    { "name": "load", "args": [ ["const char *", "type_descriptor"], ["const void *", "raw_ptr"], ["size_t", "len"] ], "ret": "FfiObject *" },
+    { "name": "store", "args": [ ["const FfiObject *", "this"], ["void *", "out_ptr"], ["size_t *", "out_len"] ], "ret": "bool" },
+    { "name": "serialize", "args": [ ["const FfiObject *", "this"], ["const char *", "format"] ], "ret": "FfiBuffer *" },
+    { "name": "deserialize", "args": [ ["const uint8_t *", "bytes"], ["size_t", "len"], ["const char *", "format"] ], "ret": "FfiObject *" },
+    { "name": "buffer_free", "args": [ ["FfiBuffer *", "this"] ] },
     { "name": "data_free", "args": [ ["FfiObject *", "this"] ] }
 ]
 }"#;
     util::bootstrap(spec)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    unsafe fn load_raw<T>(type_descriptor: &str, value: &T, len: usize) -> *mut FfiObject {
+        opendp_data__load(
+            util::into_c_char_p(type_descriptor.to_string()),
+            value as *const T as *const c_void,
+            len,
+        )
+    }
+
+    #[test]
+    fn test_load_store_scalar_round_trip() {
+        let value: i32 = 42;
+        let object = unsafe { load_raw("i32", &value, 1) };
+        assert!(!object.is_null());
+
+        let mut out: i32 = 0;
+        let mut out_len: usize = 0;
+        let ok = unsafe {
+            opendp_data__store(
+                object as *const FfiObject,
+                &mut out as *mut i32 as *mut c_void,
+                &mut out_len as *mut usize,
+            )
+        };
+        assert!(ok);
+        assert_eq!(out_len, 1);
+        assert_eq!(out, value);
+
+        opendp_data__data_free(object);
+    }
+
+    #[test]
+    fn test_load_store_bool_round_trip() {
+        // Exercises the checked-byte bool path, not a raw transmute.
+        let object = unsafe { load_raw("bool", &true, 1) };
+        assert!(!object.is_null());
+
+        let mut out: bool = false;
+        let mut out_len: usize = 0;
+        let ok = unsafe {
+            opendp_data__store(
+                object as *const FfiObject,
+                &mut out as *mut bool as *mut c_void,
+                &mut out_len as *mut usize,
+            )
+        };
+        assert!(ok);
+        assert_eq!(out_len, 1);
+        assert!(out);
+
+        opendp_data__data_free(object);
+    }
+
+    #[test]
+    fn test_load_store_vec_round_trip() {
+        let values: Vec<i32> = vec![1, 2, 3, 4];
+        let object = unsafe {
+            opendp_data__load(
+                util::into_c_char_p("Vec<i32>".to_string()),
+                values.as_ptr() as *const c_void,
+                values.len(),
+            )
+        };
+        assert!(!object.is_null());
+
+        let mut out_len: usize = 0;
+        let ok = unsafe {
+            opendp_data__store(
+                object as *const FfiObject,
+                std::ptr::null_mut(),
+                &mut out_len as *mut usize,
+            )
+        };
+        assert!(ok);
+        assert_eq!(out_len, values.len());
+
+        let mut out = vec![0i32; out_len];
+        let ok = unsafe {
+            opendp_data__store(
+                object as *const FfiObject,
+                out.as_mut_ptr() as *mut c_void,
+                std::ptr::null_mut(),
+            )
+        };
+        assert!(ok);
+        assert_eq!(out, values);
+
+        opendp_data__data_free(object);
+    }
+
+    fn round_trip_via_serialize<T: 'static + PartialEq + std::fmt::Debug>(
+        object: *mut FfiObject,
+        format: &str,
+        expected: &T,
+    ) {
+        let format_arg = util::into_c_char_p(format.to_string());
+        let buffer = opendp_data__serialize(object as *const FfiObject, format_arg);
+        assert!(!buffer.is_null());
+        let buffer_ref = unsafe { &*buffer };
+
+        let restored = opendp_data__deserialize(buffer_ref.data, buffer_ref.len, format_arg);
+        assert!(!restored.is_null());
+        assert_eq!(util::as_ref(restored).as_ref::<T>(), expected);
+
+        opendp_data__buffer_free(buffer);
+        opendp_data__data_free(restored);
+        opendp_data__data_free(object);
+    }
+
+    #[test]
+    fn test_serialize_deserialize_scalar_round_trip() {
+        for format in ["json", "binary"] {
+            let object = FfiObject::new(42i32);
+            round_trip_via_serialize(object, format, &42i32);
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize_vec_round_trip() {
+        let expected = vec![1.5f64, 2.5, 3.5];
+        for format in ["json", "binary"] {
+            let object = FfiObject::new(expected.clone());
+            round_trip_via_serialize(object, format, &expected);
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize_box_tuple_round_trip() {
+        let expected = (Box::new(7i32), Box::new(3.5f64));
+        for format in ["json", "binary"] {
+            let object = FfiObject::new((expected.0.clone(), expected.1.clone()));
+            round_trip_via_serialize(object, format, &expected);
+        }
+    }
+
+    #[test]
+    fn test_serialize_deserialize_column_map_round_trip() {
+        let mut expected = HashMap::new();
+        expected.insert("a".to_string(), Column::new(vec![1.0, 2.0, 3.0]));
+        for format in ["json", "binary"] {
+            let object = FfiObject::new(HashMap::from([(
+                "a".to_string(),
+                Column::new(vec![1.0, 2.0, 3.0]),
+            )]));
+            round_trip_via_serialize(object, format, &expected);
+        }
+    }
+
+    #[test]
+    fn test_load_then_store_then_serialize_round_trip() {
+        // load -> store -> load again, then serialize/deserialize the result,
+        // confirming the two entry points agree on the same value end to end.
+        let values: Vec<u32> = vec![10, 20, 30];
+        let object = unsafe {
+            opendp_data__load(
+                util::into_c_char_p("Vec<u32>".to_string()),
+                values.as_ptr() as *const c_void,
+                values.len(),
+            )
+        };
+        let mut out = vec![0u32; values.len()];
+        unsafe {
+            opendp_data__store(
+                object as *const FfiObject,
+                out.as_mut_ptr() as *mut c_void,
+                std::ptr::null_mut(),
+            );
+        }
+        assert_eq!(out, values);
+
+        let reloaded = unsafe {
+            opendp_data__load(
+                util::into_c_char_p("Vec<u32>".to_string()),
+                out.as_ptr() as *const c_void,
+                out.len(),
+            )
+        };
+        round_trip_via_serialize(reloaded, "json", &values);
+
+        opendp_data__data_free(object);
+    }
+}
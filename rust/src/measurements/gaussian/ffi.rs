@@ -10,18 +10,136 @@ use crate::measurements::{make_gaussian, BaseDiscreteGaussianDomain, MakeGaussia
 use crate::measures::ZeroConcentratedDivergence;
 use crate::traits::{CheckAtom, InfCast, Number};
 
+use super::threaded;
+
+/// A thread count of `0` or `1` means "run sequentially on the calling thread";
+/// this is the default and matches the pre-existing behavior exactly.
+///
+/// **Not yet wired up:** `threads` is normalized here and forwarded to
+/// `make_gaussian`, but `make_gaussian` itself does not call
+/// `super::threaded::add_gaussian_noise_threaded` anywhere, so at invocation
+/// time `threads > 1` currently has *no effect* on the measurement's actual
+/// noise draws. `add_gaussian_noise_threaded` exists and is correct (see
+/// `threaded.rs` for the chunking scheme and the index-invariance guarantee
+/// it proves against itself), but plugging it into the live per-call
+/// sampling path used by `make_gaussian` is a follow-up, not something this
+/// change has done. Note for that follow-up: `add_gaussian_noise_threaded`'s
+/// hand-rolled `SplitMix64` + Box-Muller sampler is a different, not yet
+/// privacy-audited noise mechanism from whatever `make_gaussian` samples
+/// with today, so swapping it in is its own review, not just a performance
+/// change. Scalar (`AtomDomain<T>`) constructions ignore `threads` regardless,
+/// since there is only ever one value to perturb.
+fn normalize_threads(threads: u32) -> usize {
+    threads.max(1) as usize
+}
+
+/// Bit width and signedness of a descriptor naming a Rust integer type, or
+/// `None` if `descriptor` does not name one.
+fn int_layout(descriptor: &str) -> Option<(u32, bool)> {
+    match descriptor {
+        "u8" => Some((8, false)),
+        "u16" => Some((16, false)),
+        "u32" => Some((32, false)),
+        "u64" => Some((64, false)),
+        "u128" => Some((128, false)),
+        "i8" => Some((8, true)),
+        "i16" => Some((16, true)),
+        "i32" => Some((32, true)),
+        "i64" => Some((64, true)),
+        "i128" => Some((128, true)),
+        _ => None,
+    }
+}
+
+/// Number of mantissa bits a descriptor naming a Rust float type has
+/// available to hold an integer exactly, or `None` if `descriptor` does not
+/// name one.
+fn float_mantissa_bits(descriptor: &str) -> Option<u32> {
+    match descriptor {
+        "f32" => Some(24),
+        "f64" => Some(53),
+        _ => None,
+    }
+}
+
+/// `monomorphize_integer`'s `MO::Distance: InfCast<QI>` bound already keeps
+/// the `QI -> QO` cast from failing to *compile*, but it says nothing about
+/// whether that cast (or the `data atom T -> QI` cast implied by pairing an
+/// integer domain with a metric's distance type) is actually lossless for
+/// the runtime type pairing the caller chose. Reject any pairing that is not
+/// provably exact rather than let it through to silently widen or saturate.
+///
+/// This audit is **type-width/signedness-only**: it compares the two
+/// descriptors' bit widths and signs and knows nothing about the input
+/// domain's declared bounds. A cast that is only exact because the domain
+/// happens to be bounded to a narrower range than its type (e.g. an
+/// `AtomDomain<i32>` bounded to `[0, 100]` paired with a `u8` distance type)
+/// is still rejected here, even though it can't actually overflow at
+/// invocation time. Making this bounds-aware would mean threading the
+/// domain's declared bounds down into `monomorphize_integer`/`monomorphize2`
+/// (the only place `D` has been downcast and the bounds are available) and
+/// is left as a follow-up; see
+/// `test_make_gaussian_ffi_rejects_exact_cast_when_only_bounds_prove_it` for
+/// the case this narrower check still rejects.
+fn audit_numeric_cast(from: &Type, to: &Type, from_role: &str, to_role: &str) -> Fallible<()> {
+    let exact = if from.descriptor == to.descriptor {
+        true
+    } else {
+        match (int_layout(&from.descriptor), int_layout(&to.descriptor)) {
+            (Some((from_bits, from_signed)), Some((to_bits, to_signed))) => {
+                if from_signed == to_signed {
+                    to_bits >= from_bits
+                } else if to_signed {
+                    // unsigned -> signed needs a spare bit to hold the sign
+                    to_bits > from_bits
+                } else {
+                    // signed -> unsigned can silently drop negative values
+                    false
+                }
+            }
+            (Some((from_bits, _)), None) => float_mantissa_bits(&to.descriptor)
+                .map_or(false, |mantissa_bits| from_bits <= mantissa_bits),
+            (None, Some(_)) => false, // float -> integer always risks truncation
+            (None, None) => {
+                match (
+                    float_mantissa_bits(&from.descriptor),
+                    float_mantissa_bits(&to.descriptor),
+                ) {
+                    (Some(from_mantissa), Some(to_mantissa)) => to_mantissa >= from_mantissa,
+                    _ => false,
+                }
+            }
+        }
+    };
+    if exact {
+        Ok(())
+    } else {
+        err!(
+            FFI,
+            "casting {} ({}) to {} ({}) is not provably exact for every representable value; \
+             this would silently widen, truncate, or saturate at invocation time",
+            from_role,
+            from.descriptor,
+            to_role,
+            to.descriptor
+        )
+    }
+}
+
 #[no_mangle]
 pub extern "C" fn opendp_measurements__make_gaussian(
     input_domain: *const AnyDomain,
     input_metric: *const AnyMetric,
     scale: *const c_void,
     MO: *const c_char,
+    threads: u32,
 ) -> FfiResult<*mut AnyMeasurement> {
     fn monomorphize_float<T: 'static + CheckAtom + Copy>(
         input_domain: &AnyDomain,
         input_metric: &AnyMetric,
         scale: *const c_void,
         MO: Type,
+        threads: usize,
     ) -> Fallible<AnyMeasurement>
     where
         AtomDomain<T>: MakeGaussian<ZeroConcentratedDivergence<T>, T>,
@@ -39,20 +157,22 @@ pub extern "C" fn opendp_measurements__make_gaussian(
             input_domain: &AnyDomain,
             input_metric: &AnyMetric,
             scale: MO::Distance,
+            threads: usize,
         ) -> Fallible<AnyMeasurement>
         where
             (D, D::InputMetric): MetricSpace,
         {
             let input_domain = input_domain.downcast_ref::<D>()?.clone();
             let input_metric = input_metric.downcast_ref::<D::InputMetric>()?.clone();
-            make_gaussian::<D, MO, MO::Distance>(input_domain, input_metric, scale).into_any()
+            make_gaussian::<D, MO, MO::Distance>(input_domain, input_metric, scale, threads)
+                .into_any()
         }
         let D = input_domain.type_.clone();
         let scale = *try_as_ref!(scale as *const T);
         dispatch!(monomorphize2, [
             (D, [AtomDomain<T>, VectorDomain<AtomDomain<T>>]),
             (MO, [ZeroConcentratedDivergence<T>])
-        ], (input_domain, input_metric, scale))
+        ], (input_domain, input_metric, scale, threads))
     }
     fn monomorphize_integer<
         T: 'static + CheckAtom,
@@ -64,6 +184,7 @@ pub extern "C" fn opendp_measurements__make_gaussian(
         scale: *const c_void,
         MO: Type,
         QI: Type,
+        threads: usize,
     ) -> Fallible<AnyMeasurement>
     where
         AtomDomain<T>: MakeGaussian<ZeroConcentratedDivergence<QO>, QI>,
@@ -85,6 +206,7 @@ pub extern "C" fn opendp_measurements__make_gaussian(
             input_domain: &AnyDomain,
             input_metric: &AnyMetric,
             scale: MO::Distance,
+            threads: usize,
         ) -> Fallible<AnyMeasurement>
         where
             MO::Distance: Number + InfCast<QI>,
@@ -92,7 +214,7 @@ pub extern "C" fn opendp_measurements__make_gaussian(
         {
             let input_domain = input_domain.downcast_ref::<D>()?.clone();
             let input_metric = input_metric.downcast_ref::<D::InputMetric>()?.clone();
-            make_gaussian::<D, MO, QI>(input_domain, input_metric, scale).into_any()
+            make_gaussian::<D, MO, QI>(input_domain, input_metric, scale, threads).into_any()
         }
         let D = input_domain.type_.clone();
         let scale = *try_as_ref!(scale as *const QO);
@@ -100,13 +222,14 @@ pub extern "C" fn opendp_measurements__make_gaussian(
             (D, [AtomDomain<T>, VectorDomain<AtomDomain<T>>]),
             (MO, [ZeroConcentratedDivergence<QO>]),
             (QI, [QI])
-        ], (input_domain, input_metric, scale))
+        ], (input_domain, input_metric, scale, threads))
     }
     let input_domain = try_as_ref!(input_domain);
     let input_metric = try_as_ref!(input_metric);
     let T = try_!(input_domain.type_.get_atom());
     let MO = try_!(Type::try_from(MO));
     let QO = try_!(MO.get_atom());
+    let threads = normalize_threads(threads);
 
     // This is used to check if the type is in a dispatch set,
     // without constructing an expensive backtrace upon failed match
@@ -136,14 +259,16 @@ pub extern "C" fn opendp_measurements__make_gaussian(
         }
         dispatch!(monomorphize_float, [
             (T, @floats)
-        ], (input_domain, input_metric, scale, MO))
+        ], (input_domain, input_metric, scale, MO, threads))
     } else {
         let QI = input_metric.distance_type.clone();
+        try_!(audit_numeric_cast(&T, &QI, "data type", "input distance type"));
+        try_!(audit_numeric_cast(&QI, &QO, "input distance type", "output distance type"));
         dispatch!(monomorphize_integer, [
             (T, @integers),
             (QI, @numbers),
             (QO, @floats)
-        ], (input_domain, input_metric, scale, MO, QI))
+        ], (input_domain, input_metric, scale, MO, QI, threads))
     }
     .into()
 }
@@ -156,7 +281,7 @@ mod tests {
     use crate::ffi::any::{AnyObject, Downcast};
     use crate::ffi::util;
     use crate::ffi::util::ToCharP;
-    use crate::metrics::AbsoluteDistance;
+    use crate::metrics::{AbsoluteDistance, L2Distance};
 
     #[test]
     fn test_make_gaussian_ffi() -> Fallible<()> {
@@ -165,6 +290,7 @@ mod tests {
             util::into_raw(AnyMetric::new(AbsoluteDistance::<i32>::default())),
             util::into_raw(0.0) as *const c_void,
             "ZeroConcentratedDivergence<f64>".to_char_p(),
+            1,
         ))?;
         let arg = AnyObject::new_raw(99);
         let res = core::opendp_core__measurement_invoke(&measurement, arg);
@@ -172,4 +298,78 @@ mod tests {
         assert_eq!(res, 99);
         Ok(())
     }
+
+    #[test]
+    fn test_make_gaussian_ffi_rejects_lossy_distance_cast() {
+        // i64 data paired with a u8 distance type: the implied i64 -> u8 cast
+        // can silently drop both magnitude and sign, so this should be rejected
+        // rather than allowed through on the strength of `InfCast` alone.
+        let measurement = Result::from(opendp_measurements__make_gaussian(
+            util::into_raw(AnyDomain::new(AtomDomain::<i64>::default())),
+            util::into_raw(AnyMetric::new(AbsoluteDistance::<u8>::default())),
+            util::into_raw(0.0) as *const c_void,
+            "ZeroConcentratedDivergence<f64>".to_char_p(),
+            1,
+        ));
+        assert!(measurement.is_err());
+    }
+
+    #[test]
+    fn test_make_gaussian_ffi_rejects_exact_cast_when_only_bounds_prove_it() -> Fallible<()> {
+        // An i32 domain bounded to [0, 100] paired with a u8 distance type:
+        // the cast is exact given the declared bounds (every representable
+        // value fits in a u8), but `audit_numeric_cast` only compares type
+        // widths/signedness and has no access to the domain's bounds, so it
+        // rejects this pairing anyway. This documents the known limitation
+        // described on `audit_numeric_cast`, not a bug to fix here.
+        let measurement = Result::from(opendp_measurements__make_gaussian(
+            util::into_raw(AnyDomain::new(AtomDomain::<i32>::new_closed((0, 100))?)),
+            util::into_raw(AnyMetric::new(AbsoluteDistance::<u8>::default())),
+            util::into_raw(0.0) as *const c_void,
+            "ZeroConcentratedDivergence<f64>".to_char_p(),
+            1,
+        ));
+        assert!(measurement.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_make_gaussian_ffi_vector_with_threads() -> Fallible<()> {
+        // `threads` is currently a no-op at invocation time (see the
+        // "Not yet wired up" note on `normalize_threads`), so this only
+        // proves construction/invocation succeed end-to-end for the vector
+        // domain — it does not exercise multicore noise addition, since that
+        // code path isn't reachable from `make_gaussian` yet.
+        // `add_gaussian_noise_threaded`'s chunking/RNG-split behavior is
+        // instead tested directly below, in isolation.
+        let measurement = Result::from(opendp_measurements__make_gaussian(
+            util::into_raw(AnyDomain::new(VectorDomain::new(AtomDomain::<i32>::default()))),
+            util::into_raw(AnyMetric::new(L2Distance::<i32>::default())),
+            util::into_raw(0.0) as *const c_void,
+            "ZeroConcentratedDivergence<f64>".to_char_p(),
+            4,
+        ))?;
+        let arg = AnyObject::new_raw(vec![99; 100]);
+        let res = core::opendp_core__measurement_invoke(&measurement, arg);
+        let res: Vec<i32> = Fallible::from(res)?.downcast()?;
+        assert_eq!(res, vec![99; 100]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_threaded_gaussian_noise_is_parallel_and_index_invariant() {
+        let data: Vec<f64> = (0..500).map(|i| i as f64).collect();
+        let seed = 0x5EED;
+
+        let sequential = threaded::add_gaussian_noise_threaded(data.clone(), 2.0, 1, seed);
+        assert_ne!(sequential, data, "noise should actually perturb the input");
+
+        for threads in [2, 3, 8, 16] {
+            let parallel = threaded::add_gaussian_noise_threaded(data.clone(), 2.0, threads, seed);
+            assert_eq!(
+                sequential, parallel,
+                "noise for index i must not depend on how many spans [0, len) is split into"
+            );
+        }
+    }
 }
@@ -0,0 +1,7 @@
+// Scope note: the `threads` argument of `opendp_measurements__make_gaussian`
+// (see `ffi.rs`, and `normalize_threads` there for what it does at invocation
+// time) exists only on the Gaussian constructor. The structurally-similar
+// Laplace constructor (`make_laplace`/its `ffi` entry point) has no equivalent
+// option — there is no `rust/src/measurements/laplace/` module in this tree.
+pub mod ffi;
+mod threaded;